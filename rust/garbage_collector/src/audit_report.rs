@@ -0,0 +1,287 @@
+use chrono::{DateTime, Utc};
+use chroma_error::{ChromaError, ErrorCodes};
+use chroma_storage::{PutOptions, Storage, StorageError};
+use chroma_types::CollectionUuid;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// One notable thing that happened during a GC run, recorded alongside the
+/// `tracing` call that reports it so the same information survives past the
+/// ambient log. Mirrors the level/message shape of a `tracing` event rather
+/// than trying to capture its structured fields generically.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcAuditEvent {
+    pub timestamp: DateTime<Utc>,
+    pub level: String,
+    pub message: String,
+}
+
+/// Accumulates [`GcAuditEvent`]s for a single orchestrator run.
+///
+/// Events get in here one of two ways: [`AuditRecorder::record`], called
+/// explicitly alongside a `tracing::*!` call, or transparently, via
+/// [`AuditLayer`] - once a recorder is [attached][AuditRecorder::attach_to]
+/// to a span, every event emitted inside that span (and its children) is
+/// captured automatically, the way a request-scoped log collector would,
+/// with no risk of the log call and the record call drifting apart.
+/// `record` still exists for events worth noting that don't already have
+/// (or don't want) a matching `tracing` call.
+#[derive(Debug, Clone, Default)]
+pub struct AuditRecorder {
+    events: Arc<Mutex<Vec<GcAuditEvent>>>,
+}
+
+impl AuditRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, level: &str, message: impl Into<String>) {
+        let mut events = self.events.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        events.push(GcAuditEvent {
+            timestamp: Utc::now(),
+            level: level.to_string(),
+            message: message.into(),
+        });
+    }
+
+    pub fn drain(&self) -> Vec<GcAuditEvent> {
+        let mut events = self.events.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        std::mem::take(&mut events)
+    }
+
+    /// Registers a clone of `self` as the audit sink for every event emitted
+    /// inside `span` for the rest of its life, via [`AuditLayer`]. A no-op if
+    /// `AuditLayer` isn't installed on the process's subscriber (e.g. in a
+    /// test without one set up); events then only reach the report through
+    /// explicit [`AuditRecorder::record`] calls, same as before this existed.
+    pub fn attach_to(&self, span: &tracing::Span) {
+        span.with_subscriber(|(id, dispatch)| {
+            if let Some(registry) = dispatch.downcast_ref::<tracing_subscriber::Registry>() {
+                if let Some(span_ref) = registry.span(id) {
+                    span_ref.extensions_mut().insert(self.clone());
+                }
+            }
+        });
+    }
+}
+
+/// Extracts an event's formatted `message` field, the same text a `fmt`
+/// layer would print, so a captured [`GcAuditEvent`] reads like the log line
+/// it stands in for.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+/// A `tracing_subscriber::Layer` that makes [`AuditRecorder::attach_to`]
+/// work: for every event, walks up from its span to the nearest ancestor an
+/// `AuditRecorder` was attached to (if any) and records the event there.
+/// Must be added to the process's subscriber (e.g.
+/// `tracing_subscriber::registry().with(AuditLayer)`) for attached recorders
+/// to actually capture anything.
+#[derive(Debug, Default)]
+pub struct AuditLayer;
+
+impl<S> Layer<S> for AuditLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(scope) = ctx.event_scope(event) else {
+            return;
+        };
+        for span in scope {
+            let extensions = span.extensions();
+            if let Some(recorder) = extensions.get::<AuditRecorder>() {
+                let mut visitor = MessageVisitor::default();
+                event.record(&mut visitor);
+                recorder.record(event.metadata().level().as_str(), visitor.0);
+                break;
+            }
+        }
+    }
+}
+
+/// A structured, queryable record of one GC run, persisted to storage so an
+/// operator can retrieve exactly what a past run did instead of grepping
+/// through the global log.
+///
+/// Its storage key isn't threaded through `GarbageCollectorResponse` because
+/// that type lives in `crate::types`, outside this module's reach, and isn't
+/// part of this checkout - there's no field on it to populate. Until it grows
+/// one, `GcAuditReportStore::save`'s return value is the key's only consumer:
+/// it's logged at info level next to the collection id so an operator
+/// watching logs can still find the report for a given run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GcAuditReport {
+    pub collection_id: CollectionUuid,
+    pub run_started_at: DateTime<Utc>,
+    pub run_completed_at: DateTime<Utc>,
+    pub dry_run: bool,
+    pub num_files_considered: u32,
+    pub num_files_deleted: u32,
+    /// Files deleted by the orphan-repair pass specifically, already
+    /// included in `num_files_deleted` - broken out so an operator can tell
+    /// normal GC reclamation apart from orphan repair.
+    pub num_orphan_files_deleted: u32,
+    pub num_versions_deleted: u32,
+    pub versions_marked_for_deletion: HashMap<CollectionUuid, Vec<i64>>,
+    /// Collections hard-deleted this run, in the order they were deleted
+    /// (reverse topological, i.e. children before parents).
+    pub collections_hard_deleted_in_order: Vec<CollectionUuid>,
+    /// Collections excluded from hard-deletion this run because a corrupt
+    /// lineage file put them in a dependency cycle (or because they're an
+    /// ancestor of one that did). Empty in the common case.
+    pub quarantined_collections: Vec<CollectionUuid>,
+    /// The specific lineage edges that close each quarantined cycle, so an
+    /// operator can see exactly which parent/child links are wrong instead
+    /// of just which collections got excluded.
+    pub quarantined_lineage_edges: Vec<(CollectionUuid, CollectionUuid)>,
+    pub events: Vec<GcAuditEvent>,
+}
+
+#[derive(Error, Debug)]
+pub enum GcAuditReportError {
+    #[error("Failed to write GC audit report: {0}")]
+    Write(#[from] StorageError),
+    #[error("Failed to serialize GC audit report: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+impl ChromaError for GcAuditReportError {
+    fn code(&self) -> ErrorCodes {
+        ErrorCodes::Internal
+    }
+}
+
+fn audit_report_key(collection_id: CollectionUuid, run_started_at: DateTime<Utc>) -> String {
+    format!(
+        "gc/audit_reports/{}/{}.json",
+        collection_id,
+        run_started_at.timestamp()
+    )
+}
+
+#[derive(Debug, Clone)]
+pub struct GcAuditReportStore {
+    storage: Storage,
+}
+
+impl GcAuditReportStore {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+
+    /// Persists `report` and returns the storage key it was written to, i.e.
+    /// the report's location.
+    pub async fn save(&self, report: &GcAuditReport) -> Result<String, GcAuditReportError> {
+        let key = audit_report_key(report.collection_id, report.run_started_at);
+        let bytes = serde_json::to_vec(report)?;
+        self.storage
+            .put_bytes(&key, bytes, PutOptions::default())
+            .await?;
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chroma_storage::test_storage;
+    use tracing_subscriber::prelude::*;
+
+    #[test]
+    fn record_and_drain_round_trips_events() {
+        let recorder = AuditRecorder::new();
+        recorder.record("WARN", "something happened");
+        recorder.record("INFO", "something else happened");
+
+        let events = recorder.drain();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].level, "WARN");
+        assert_eq!(events[0].message, "something happened");
+        assert_eq!(events[1].level, "INFO");
+        assert_eq!(events[1].message, "something else happened");
+    }
+
+    #[test]
+    fn drain_empties_the_recorder() {
+        let recorder = AuditRecorder::new();
+        recorder.record("INFO", "one event");
+
+        assert_eq!(recorder.drain().len(), 1);
+        assert!(recorder.drain().is_empty());
+    }
+
+    #[test]
+    fn attach_to_without_a_subscriber_is_a_no_op() {
+        // No `AuditLayer`-bearing subscriber is installed here, so there's no
+        // span extensions map to insert into - this must not panic.
+        let recorder = AuditRecorder::new();
+        recorder.attach_to(&tracing::Span::none());
+    }
+
+    #[test]
+    fn audit_layer_transparently_captures_events_within_an_attached_span() {
+        let recorder = AuditRecorder::new();
+
+        tracing::subscriber::with_default(
+            tracing_subscriber::registry().with(AuditLayer),
+            || {
+                let span = tracing::info_span!("run");
+                let _guard = span.enter();
+                recorder.attach_to(&span);
+
+                tracing::warn!("captured inside the attached span");
+            },
+        );
+
+        let events = recorder.drain();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].level, "WARN");
+        assert!(events[0].message.contains("captured inside the attached span"));
+    }
+
+    #[tokio::test]
+    async fn store_save_returns_the_key_it_wrote_to() {
+        let (_storage_dir, storage) = test_storage();
+        let store = GcAuditReportStore::new(storage.clone());
+
+        let report = GcAuditReport {
+            collection_id: CollectionUuid::new(),
+            run_started_at: Utc::now(),
+            run_completed_at: Utc::now(),
+            dry_run: false,
+            num_files_considered: 10,
+            num_files_deleted: 3,
+            num_orphan_files_deleted: 1,
+            num_versions_deleted: 2,
+            versions_marked_for_deletion: HashMap::new(),
+            collections_hard_deleted_in_order: Vec::new(),
+            quarantined_collections: Vec::new(),
+            quarantined_lineage_edges: Vec::new(),
+            events: Vec::new(),
+        };
+
+        let key = store.save(&report).await.unwrap();
+        let bytes = storage.get(&key).await.unwrap();
+        let loaded: GcAuditReport = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(loaded.collection_id, report.collection_id);
+        assert_eq!(loaded.num_orphan_files_deleted, report.num_orphan_files_deleted);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::{HashMap, HashSet};
+
+use chroma_error::{ChromaError, ErrorCodes};
+use chroma_storage::{PutOptions, Storage, StorageError};
+use chroma_types::CollectionUuid;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A snapshot of a GC run's intermediate state, persisted when a run is
+/// cooperatively aborted so the interrupted run can be diagnosed and resumed
+/// instead of always redoing the whole pass from scratch. It is not persisted
+/// at other stage boundaries, so a run that is killed outright leaves none.
+///
+/// The orchestrator's downstream stages operate on the rich
+/// `ComputeVersionsToDeleteOutput` type from `compute_versions_to_delete_from_graph`,
+/// which isn't `Serialize`, so this snapshot can't capture enough to skip
+/// reconstructing the version graph and recomputing delete decisions on
+/// startup - that part of a killed run is always redone. What it does let a
+/// resumed run skip is redoing the decisions' *destructive* side effects:
+/// `versions_marked_for_deletion` and `hard_deleted_collections` are used to
+/// avoid re-issuing sysdb writes (mark-at-sysdb, finish-collection-deletion)
+/// for work the interrupted run already completed. `file_ref_counts` remains
+/// diagnostic only, for post-mortem inspection of a killed run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GcCheckpoint {
+    pub versions_marked_for_deletion: HashMap<CollectionUuid, Vec<i64>>,
+    pub file_ref_counts: HashMap<String, u32>,
+    pub hard_deleted_collections: HashSet<CollectionUuid>,
+}
+
+#[derive(Error, Debug)]
+pub enum GcCheckpointError {
+    #[error("Failed to read GC checkpoint: {0}")]
+    Read(#[from] StorageError),
+    #[error("Failed to (de)serialize GC checkpoint: {0}")]
+    Serde(#[from] serde_json::Error),
+}
+
+impl ChromaError for GcCheckpointError {
+    fn code(&self) -> ErrorCodes {
+        ErrorCodes::Internal
+    }
+}
+
+fn checkpoint_key(root_collection_id: CollectionUuid) -> String {
+    format!("gc/checkpoints/{}.json", root_collection_id)
+}
+
+#[derive(Debug, Clone)]
+pub struct GcCheckpointStore {
+    storage: Storage,
+}
+
+impl GcCheckpointStore {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+
+    pub async fn load(&self, root_collection_id: CollectionUuid) -> Option<GcCheckpoint> {
+        match self.storage.get(&checkpoint_key(root_collection_id)).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).ok(),
+            Err(_) => None,
+        }
+    }
+
+    pub async fn save(
+        &self,
+        root_collection_id: CollectionUuid,
+        checkpoint: &GcCheckpoint,
+    ) -> Result<(), GcCheckpointError> {
+        let bytes = serde_json::to_vec(checkpoint)?;
+        self.storage
+            .put_bytes(
+                &checkpoint_key(root_collection_id),
+                bytes,
+                PutOptions::default(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Removes the checkpoint once a run completes successfully, so a future
+    /// run doesn't mistake this collection for one that was interrupted.
+    pub async fn clear(&self, root_collection_id: CollectionUuid) -> Result<(), GcCheckpointError> {
+        match self.storage.delete(&checkpoint_key(root_collection_id)).await {
+            Ok(()) | Err(StorageError::NotFound { .. }) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chroma_storage::test_storage;
+
+    #[tokio::test]
+    async fn round_trips_through_save_and_load() {
+        let (_storage_dir, storage) = test_storage();
+        let store = GcCheckpointStore::new(storage);
+        let collection_id = CollectionUuid::new();
+
+        let checkpoint = GcCheckpoint {
+            versions_marked_for_deletion: HashMap::from([(collection_id, vec![1, 2, 3])]),
+            file_ref_counts: HashMap::from([("some/file".to_string(), 2)]),
+            hard_deleted_collections: HashSet::from([CollectionUuid::new()]),
+        };
+
+        store.save(collection_id, &checkpoint).await.unwrap();
+        let loaded = store.load(collection_id).await.unwrap();
+
+        assert_eq!(
+            loaded.versions_marked_for_deletion,
+            checkpoint.versions_marked_for_deletion
+        );
+        assert_eq!(loaded.file_ref_counts, checkpoint.file_ref_counts);
+        assert_eq!(
+            loaded.hard_deleted_collections,
+            checkpoint.hard_deleted_collections
+        );
+    }
+
+    #[tokio::test]
+    async fn load_returns_none_when_no_checkpoint_exists() {
+        let (_storage_dir, storage) = test_storage();
+        let store = GcCheckpointStore::new(storage);
+
+        assert!(store.load(CollectionUuid::new()).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn clear_removes_the_checkpoint() {
+        let (_storage_dir, storage) = test_storage();
+        let store = GcCheckpointStore::new(storage);
+        let collection_id = CollectionUuid::new();
+
+        store
+            .save(collection_id, &GcCheckpoint::default())
+            .await
+            .unwrap();
+        assert!(store.load(collection_id).await.is_some());
+
+        store.clear(collection_id).await.unwrap();
+        assert!(store.load(collection_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn clear_is_a_no_op_when_no_checkpoint_exists() {
+        let (_storage_dir, storage) = test_storage();
+        let store = GcCheckpointStore::new(storage);
+
+        store.clear(CollectionUuid::new()).await.unwrap();
+    }
+}
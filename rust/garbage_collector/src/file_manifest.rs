@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use chroma_error::{ChromaError, ErrorCodes};
+use chroma_storage::{PutOptions, Storage, StorageError};
+use chroma_types::CollectionUuid;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A cached summary of the file set a collection's GC runs have already
+/// accounted for, keyed by the highest version folded into it. Lets a run
+/// skip re-listing files for every version in the fork tree on every pass:
+/// only versions newer than `highest_version_seen` need a fresh
+/// `ListFilesAtVersionsOperator` call, and their results are folded into
+/// `live_file_paths` the way an aggregation tree folds a delta up to its
+/// parent instead of re-summing the whole subtree.
+///
+/// `oldest_kept_version` is the retention-window boundary at save time: the
+/// lowest version number that was still `Keep` (not yet reclaimed) when this
+/// manifest was written. Retention only moves forward, so if that same
+/// version is still `Keep` on a later run, nothing this manifest counted as
+/// live can have been deleted since; if it has flipped to `Delete` (aged out
+/// of `min_versions_to_keep`), some version between it and
+/// `highest_version_seen` may have flipped too, and the cached
+/// `live_file_paths` can no longer be trusted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionFileManifest {
+    pub highest_version_seen: i64,
+    pub oldest_kept_version: i64,
+    pub live_file_paths: HashSet<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum FileManifestError {
+    #[error("Failed to read file manifest: {0}")]
+    Read(#[from] StorageError),
+    #[error("Failed to deserialize file manifest: {0}")]
+    Deserialize(#[from] serde_json::Error),
+}
+
+impl ChromaError for FileManifestError {
+    fn code(&self) -> ErrorCodes {
+        ErrorCodes::Internal
+    }
+}
+
+fn manifest_key(collection_id: CollectionUuid) -> String {
+    format!("gc/file_manifests/{}.json", collection_id)
+}
+
+/// Loads and saves [`CollectionFileManifest`]s for the garbage collector.
+/// Falls back to `None` on any read error (missing key, corrupt content) so
+/// callers can treat "no usable manifest" the same as "first run for this
+/// collection" and recompute from scratch.
+#[derive(Debug, Clone)]
+pub struct FileManifestStore {
+    storage: Storage,
+}
+
+impl FileManifestStore {
+    pub fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+
+    pub async fn load(&self, collection_id: CollectionUuid) -> Option<CollectionFileManifest> {
+        match self.try_load(collection_id).await {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                tracing::debug!(
+                    "No usable file manifest for collection {}, falling back to full recompute: {}",
+                    collection_id,
+                    err
+                );
+                None
+            }
+        }
+    }
+
+    async fn try_load(
+        &self,
+        collection_id: CollectionUuid,
+    ) -> Result<Option<CollectionFileManifest>, FileManifestError> {
+        let bytes = match self.storage.get(&manifest_key(collection_id)).await {
+            Ok(bytes) => bytes,
+            Err(StorageError::NotFound { .. }) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Some(serde_json::from_slice(&bytes)?))
+    }
+
+    pub async fn save(
+        &self,
+        collection_id: CollectionUuid,
+        manifest: &CollectionFileManifest,
+    ) -> Result<(), FileManifestError> {
+        let bytes = serde_json::to_vec(manifest)?;
+        self.storage
+            .put_bytes(&manifest_key(collection_id), bytes, PutOptions::default())
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chroma_storage::test_storage;
+
+    #[tokio::test]
+    async fn round_trips_through_save_and_load() {
+        let (_storage_dir, storage) = test_storage();
+        let store = FileManifestStore::new(storage);
+        let collection_id = CollectionUuid::new();
+
+        let manifest = CollectionFileManifest {
+            highest_version_seen: 7,
+            oldest_kept_version: 4,
+            live_file_paths: HashSet::from(["a/b".to_string(), "c/d".to_string()]),
+        };
+
+        store.save(collection_id, &manifest).await.unwrap();
+        let loaded = store.load(collection_id).await.unwrap();
+
+        assert_eq!(loaded.highest_version_seen, manifest.highest_version_seen);
+        assert_eq!(loaded.oldest_kept_version, manifest.oldest_kept_version);
+        assert_eq!(loaded.live_file_paths, manifest.live_file_paths);
+    }
+
+    #[tokio::test]
+    async fn load_falls_back_to_none_when_no_manifest_exists() {
+        let (_storage_dir, storage) = test_storage();
+        let store = FileManifestStore::new(storage);
+
+        assert!(store.load(CollectionUuid::new()).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn load_falls_back_to_none_on_corrupt_content() {
+        let (_storage_dir, storage) = test_storage();
+        let store = FileManifestStore::new(storage.clone());
+        let collection_id = CollectionUuid::new();
+
+        storage
+            .put_bytes(&manifest_key(collection_id), b"not json".to_vec(), PutOptions::default())
+            .await
+            .unwrap();
+
+        assert!(store.load(collection_id).await.is_none());
+    }
+}
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
+use crate::audit_report::{AuditRecorder, GcAuditReport, GcAuditReportStore};
+use crate::checkpoint::{GcCheckpoint, GcCheckpointStore};
 use crate::construct_version_graph_orchestrator::{
     ConstructVersionGraphError, ConstructVersionGraphOrchestrator,
 };
+use crate::file_manifest::{CollectionFileManifest, FileManifestStore};
 use crate::operators::compute_versions_to_delete_from_graph::{
     CollectionVersionAction, ComputeVersionsToDeleteError, ComputeVersionsToDeleteInput,
     ComputeVersionsToDeleteOperator, ComputeVersionsToDeleteOutput,
@@ -26,6 +29,11 @@ use crate::operators::mark_versions_at_sysdb::{
     MarkVersionsAtSysDbError, MarkVersionsAtSysDbInput, MarkVersionsAtSysDbOperator,
     MarkVersionsAtSysDbOutput,
 };
+use crate::operators::repair_orphan_files::{
+    RepairOrphanFilesError, RepairOrphanFilesInput, RepairOrphanFilesOperator,
+    RepairOrphanFilesOutput,
+};
+use crate::retention_policy::RetentionPolicy;
 use crate::types::{
     version_graph_to_collection_dependency_graph, CleanupMode, GarbageCollectorResponse,
     VersionGraph,
@@ -59,6 +67,7 @@ pub struct GarbageCollectorOrchestrator {
     lineage_file_path: Option<String>,
     version_absolute_cutoff_time: DateTime<Utc>,
     collection_soft_delete_absolute_cutoff_time: DateTime<Utc>,
+    retention_policy: RetentionPolicy,
     sysdb_client: SysDb,
     context: OrchestratorContext,
     system: System,
@@ -86,6 +95,60 @@ pub struct GarbageCollectorOrchestrator {
     num_versions_deleted: u32,
 
     enable_dangerous_option_to_ignore_min_versions_for_wal3: bool,
+
+    enable_orphan_file_repair: bool,
+    num_orphan_files_deleted: u32,
+    orphan_repair_done: bool,
+
+    version_generation_tokens: HashMap<CollectionUuid, VersionGenerationToken>,
+
+    /// Versions `detect_aborted_versions` flagged as possibly aborted, with
+    /// the witness version responsible for each flag (see
+    /// `detect_aborted_versions`), outside `min_versions_to_keep`'s window.
+    /// Not yet trustworthy on its own - a witness that's itself a normal kept
+    /// version, not an aged-out one, is exactly the false-positive case the
+    /// heuristic can't rule out by itself. Narrowed down into
+    /// `aborted_versions` once `ComputeVersionsToDeleteOperator`'s decisions
+    /// are available to check the witness against.
+    candidate_aborted_versions: HashMap<CollectionUuid, HashMap<i64, i64>>,
+
+    /// Versions whose compaction looks interrupted: detected independently of
+    /// `version_absolute_cutoff_time`/`min_versions_to_keep`, so a crashed
+    /// flush's dangling files get reclaimed even if the version is too recent
+    /// for the normal age-based cutoff to touch. Populated from
+    /// `candidate_aborted_versions` once each candidate's witness version is
+    /// confirmed to itself be marked `Delete` - see
+    /// `handle_compute_versions_to_delete_output`.
+    aborted_versions: HashSet<(CollectionUuid, i64)>,
+
+    file_manifest_store: FileManifestStore,
+    enable_incremental_file_manifest: bool,
+    versions_covered_by_manifest: HashMap<CollectionUuid, i64>,
+
+    checkpoint_store: GcCheckpointStore,
+    enable_checkpointing: bool,
+    hard_deleted_collections: HashSet<CollectionUuid>,
+    /// Versions a previous, interrupted run for this collection had already
+    /// marked deleted at sysdb, loaded from its checkpoint at startup. Lets
+    /// this run skip re-sending `MarkVersionsAtSysDbOperator` for work that's
+    /// already done instead of redoing it from scratch.
+    resumed_marked_versions: HashMap<CollectionUuid, HashSet<i64>>,
+
+    audit_recorder: AuditRecorder,
+    audit_report_store: GcAuditReportStore,
+    enable_audit_report: bool,
+    run_started_at: DateTime<Utc>,
+
+    /// Collections excluded from hard-deletion this run because they sit in
+    /// a cycle of the lineage dependency graph, or are an ancestor of one
+    /// that does (see [`LineageCycle::ancestors`]) and so can no longer be
+    /// proven to have every forked descendant soft deleted once the cycle is
+    /// removed from the graph. See [`quarantine_lineage_cycles`].
+    quarantined_collections: HashSet<CollectionUuid>,
+    /// The lineage edges responsible for each cycle quarantined this run, so
+    /// the audit report can show an operator exactly which links are wrong
+    /// instead of just which collections got excluded.
+    quarantined_lineage_edges: Vec<(CollectionUuid, CollectionUuid)>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -106,6 +169,51 @@ impl GarbageCollectorOrchestrator {
         min_versions_to_keep: u32,
         enable_log_gc: bool,
         enable_dangerous_option_to_ignore_min_versions_for_wal3: bool,
+    ) -> Self {
+        Self::with_retention_policy(
+            collection_id,
+            version_file_path,
+            lineage_file_path,
+            version_absolute_cutoff_time,
+            collection_soft_delete_absolute_cutoff_time,
+            RetentionPolicy::single_rule(min_versions_to_keep),
+            sysdb_client,
+            dispatcher,
+            system,
+            storage,
+            logs,
+            root_manager,
+            cleanup_mode,
+            min_versions_to_keep,
+            enable_log_gc,
+            enable_dangerous_option_to_ignore_min_versions_for_wal3,
+        )
+    }
+
+    /// Like [`GarbageCollectorOrchestrator::new`], but takes an explicit
+    /// [`RetentionPolicy`] instead of deriving a single global rule from
+    /// `min_versions_to_keep`. `version_absolute_cutoff_time`,
+    /// `collection_soft_delete_absolute_cutoff_time`, and
+    /// `min_versions_to_keep` remain as the defaults a collection resolves to
+    /// when no rule in the policy matches it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_retention_policy(
+        collection_id: CollectionUuid,
+        version_file_path: String,
+        lineage_file_path: Option<String>,
+        version_absolute_cutoff_time: DateTime<Utc>,
+        collection_soft_delete_absolute_cutoff_time: DateTime<Utc>,
+        retention_policy: RetentionPolicy,
+        sysdb_client: SysDb,
+        dispatcher: ComponentHandle<Dispatcher>,
+        system: System,
+        storage: Storage,
+        logs: Log,
+        root_manager: RootManager,
+        cleanup_mode: CleanupMode,
+        min_versions_to_keep: u32,
+        enable_log_gc: bool,
+        enable_dangerous_option_to_ignore_min_versions_for_wal3: bool,
     ) -> Self {
         Self {
             collection_id,
@@ -113,9 +221,13 @@ impl GarbageCollectorOrchestrator {
             lineage_file_path,
             version_absolute_cutoff_time,
             collection_soft_delete_absolute_cutoff_time,
+            retention_policy,
             sysdb_client,
             context: OrchestratorContext::new(dispatcher),
             system,
+            file_manifest_store: FileManifestStore::new(storage.clone()),
+            checkpoint_store: GcCheckpointStore::new(storage.clone()),
+            audit_report_store: GcAuditReportStore::new(storage.clone()),
             storage,
             logs,
             root_manager,
@@ -140,8 +252,108 @@ impl GarbageCollectorOrchestrator {
             num_versions_deleted: 0,
 
             enable_dangerous_option_to_ignore_min_versions_for_wal3,
+
+            enable_orphan_file_repair: false,
+            num_orphan_files_deleted: 0,
+            orphan_repair_done: false,
+
+            version_generation_tokens: HashMap::new(),
+            candidate_aborted_versions: HashMap::new(),
+            aborted_versions: HashSet::new(),
+
+            enable_incremental_file_manifest: false,
+            versions_covered_by_manifest: HashMap::new(),
+
+            enable_checkpointing: false,
+            hard_deleted_collections: HashSet::new(),
+            resumed_marked_versions: HashMap::new(),
+
+            audit_recorder: AuditRecorder::new(),
+            enable_audit_report: false,
+            run_started_at: Utc::now(),
+
+            quarantined_collections: HashSet::new(),
+            quarantined_lineage_edges: Vec::new(),
         }
     }
+
+    /// Enables the orphan-file repair pass: after the normal GC pass computes
+    /// which files are still referenced, also list the collection lineage's
+    /// storage prefix and reconcile away anything present in storage but
+    /// absent from that reference set. Off by default since it adds a
+    /// storage list call per run.
+    pub fn with_orphan_file_repair(mut self, enable: bool) -> Self {
+        self.enable_orphan_file_repair = enable;
+        self
+    }
+
+    /// Enables loading a persisted [`CollectionFileManifest`] per collection
+    /// so a run only needs to list files for versions newer than what the
+    /// manifest already covers, instead of every version in the fork tree.
+    /// Off by default; falls back to full recompute whenever no manifest is
+    /// found.
+    pub fn with_incremental_file_manifest(mut self, enable: bool) -> Self {
+        self.enable_incremental_file_manifest = enable;
+        self
+    }
+
+    /// Enables loading a leftover [`GcCheckpoint`] at startup and persisting
+    /// one when a run is stopped via [`Self::request_abort`]. The checkpoint
+    /// is written only from `try_finalize`, once every spawned task has
+    /// finished; it is not written at other stage boundaries, so a run that
+    /// is killed outright leaves none behind. The checkpoint is cleared once
+    /// a run completes successfully.
+    pub fn with_checkpointing(mut self, enable: bool) -> Self {
+        self.enable_checkpointing = enable;
+        self
+    }
+
+    /// Enables persisting a [`GcAuditReport`] once this run finishes, keyed by
+    /// collection id and the run's start time. The report's storage key is
+    /// logged at info level when the run completes so an operator watching
+    /// logs can find it; see [`crate::audit_report`] for why it isn't (yet)
+    /// threaded through `GarbageCollectorResponse` itself.
+    pub fn with_audit_report(mut self, enable: bool) -> Self {
+        self.enable_audit_report = enable;
+        self
+    }
+
+    /// Requests a cooperative, clean stop: outstanding operator tasks are
+    /// cancelled via the same `task_cancellation_token` operators already
+    /// check, and the orchestrator flushes a checkpoint (if enabled) instead
+    /// of proceeding into the hard-delete stage with half-updated state.
+    pub fn request_abort(&self) {
+        self.context.task_cancellation_token.cancel();
+    }
+
+    /// Seeds `resumed_marked_versions`/`hard_deleted_collections` from a
+    /// checkpoint left behind by a previous, interrupted run for this
+    /// collection, so the rest of this run skips redoing the sysdb writes it
+    /// already completed. Split out of `on_start` so the seeding logic itself
+    /// is testable without the full actor lifecycle.
+    fn resume_from_checkpoint(&mut self, checkpoint: GcCheckpoint) {
+        tracing::warn!(
+            "Resuming from a leftover GC checkpoint for collection {}: {} version(s) across \
+             {} collection(s) were already marked for deletion and {} collection(s) were \
+             already hard-deleted before the previous run was interrupted. Skipping that work \
+             instead of redoing it. Checkpoint: {:#?}",
+            self.collection_id,
+            checkpoint
+                .versions_marked_for_deletion
+                .values()
+                .map(|versions| versions.len())
+                .sum::<usize>(),
+            checkpoint.versions_marked_for_deletion.len(),
+            checkpoint.hard_deleted_collections.len(),
+            checkpoint
+        );
+        self.resumed_marked_versions = checkpoint
+            .versions_marked_for_deletion
+            .into_iter()
+            .map(|(collection_id, versions)| (collection_id, versions.into_iter().collect()))
+            .collect();
+        self.hard_deleted_collections = checkpoint.hard_deleted_collections;
+    }
 }
 
 #[derive(Error, Debug)]
@@ -173,6 +385,8 @@ pub enum GarbageCollectorError {
     DeleteUnusedLogs(#[from] DeleteUnusedLogsError),
     #[error("Failed to delete versions at sysdb: {0}")]
     DeleteVersionsAtSysDb(#[from] DeleteVersionsAtSysDbError),
+    #[error("Failed to repair orphan files: {0}")]
+    RepairOrphanFiles(#[from] RepairOrphanFilesError),
 
     #[error("Expected version file missing for collection {0}")]
     MissingVersionFile(CollectionUuid),
@@ -184,6 +398,8 @@ pub enum GarbageCollectorError {
     CollectionDeletionFailed(#[from] DeleteCollectionError),
     #[error("SysDb method failed: {0}")]
     SysDbMethodFailed(String),
+    #[error("Aborting delete for collection {0} because its version file changed concurrently with this GC run")]
+    ConcurrentModification(CollectionUuid),
 }
 
 impl ChromaError for GarbageCollectorError {
@@ -208,6 +424,201 @@ where
 #[derive(Debug)]
 struct ConstructVersionGraphRequest;
 
+/// A read token captured for a collection when its version graph was built,
+/// used as a compare-and-swap stamp to detect a concurrent compaction before
+/// this orchestrator commits to deleting that collection's versions/files.
+/// Two tokens being unequal means a new version was flushed between the read
+/// and the write, so the causality the delete relies on no longer holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VersionGenerationToken {
+    max_version: i64,
+    current_log_position: i64,
+}
+
+impl VersionGenerationToken {
+    fn from_version_file(
+        version_file: &CollectionVersionFile,
+    ) -> Option<Self> {
+        let version_history = version_file.version_history.as_ref()?;
+        let latest = version_history.versions.iter().max_by_key(|v| v.version)?;
+        Some(Self {
+            max_version: latest.version,
+            current_log_position: latest
+                .collection_info_mutable
+                .as_ref()?
+                .current_log_position,
+        })
+    }
+}
+
+/// Flags versions whose compaction looks interrupted: a version is considered
+/// a *candidate* abort if a strictly newer version for the same collection
+/// exists at the same log position, meaning the flush that produced it never
+/// advanced the log before being superseded. Borrowed from the
+/// Uploading/Complete/Aborted state model object stores use for multipart
+/// writes: a version entry that never reached "complete" shouldn't wait out
+/// the normal age-based cutoff before its dangling files are reclaimed.
+///
+/// This alone is only a proxy for "uncommitted": a normal, fully-committed
+/// version can coincidentally share a log position with a later,
+/// metadata-only bump, and nothing here can tell the two apart. Returns, for
+/// each candidate, the *witness* version responsible for the flag (the
+/// earliest newer version sharing its log position) rather than a bare set,
+/// so a caller can additionally require that witness to itself be aged out
+/// before trusting the inference - see the `aborted_versions` computation in
+/// `handle_compute_versions_to_delete_output`, which is where that check
+/// actually happens.
+fn detect_aborted_versions(version_file: &CollectionVersionFile) -> HashMap<i64, i64> {
+    let Some(version_history) = version_file.version_history.as_ref() else {
+        return HashMap::new();
+    };
+
+    let max_version = version_history
+        .versions
+        .iter()
+        .map(|v| v.version)
+        .max()
+        .unwrap_or(0);
+
+    version_history
+        .versions
+        .iter()
+        .filter(|v| v.version < max_version)
+        .filter_map(|v| {
+            let log_position = v.collection_info_mutable.as_ref()?.current_log_position;
+            let witness = version_history
+                .versions
+                .iter()
+                .filter(|other| {
+                    other.version > v.version
+                        && other
+                            .collection_info_mutable
+                            .as_ref()
+                            .is_some_and(|info| info.current_log_position == log_position)
+                })
+                .map(|other| other.version)
+                .min()?;
+            Some((v.version, witness))
+        })
+        .collect()
+}
+
+/// The version numbers of the `min_versions_to_keep` most recent versions in
+/// `version_file`. Aborted-version reclamation must never reach into this
+/// window: the same-log-position heuristic in `detect_aborted_versions` is
+/// only a proxy for "uncommitted", and a normal, fully-committed version can
+/// coincidentally share a log position with a later metadata-only bump. Those
+/// recent versions are still protected by `min_versions_to_keep` the same as
+/// any other kept version.
+fn versions_within_keep_window(
+    version_file: &CollectionVersionFile,
+    min_versions_to_keep: u32,
+) -> HashSet<i64> {
+    let Some(version_history) = version_file.version_history.as_ref() else {
+        return HashSet::new();
+    };
+
+    let mut versions = version_history
+        .versions
+        .iter()
+        .map(|v| v.version)
+        .collect::<Vec<_>>();
+    versions.sort_unstable_by(|a, b| b.cmp(a));
+    versions
+        .into_iter()
+        .take(min_versions_to_keep as usize)
+        .collect()
+}
+
+/// One strongly-connected component of the collection dependency graph with
+/// more than one member, i.e. an actual cycle rather than a lone node.
+#[derive(Debug, Clone)]
+struct LineageCycle {
+    collections: Vec<CollectionUuid>,
+    /// Edges within the cycle, reported so an operator can see exactly which
+    /// lineage links are wrong instead of just which collections are involved.
+    offending_edges: Vec<(CollectionUuid, CollectionUuid)>,
+    /// Ancestors of the cycle (parents, grandparents, ...) in the graph as it
+    /// stood before the cycle's nodes were removed. These aren't themselves
+    /// cyclic, but once the cycle's nodes are gone they're no longer
+    /// reachable from them, so a reachability check run after quarantining
+    /// would wrongly conclude they have no forked descendants still alive.
+    /// Callers must exclude these from hard-deletion this run too.
+    ancestors: Vec<CollectionUuid>,
+}
+
+/// The set of nodes that can reach `start` by following edges backwards
+/// (i.e. `start`'s parents, grandparents, ...), not including `start` itself.
+fn ancestors_of(
+    graph: &petgraph::graphmap::DiGraphMap<CollectionUuid, ()>,
+    start: CollectionUuid,
+) -> HashSet<CollectionUuid> {
+    let mut ancestors = HashSet::new();
+    let mut to_visit = vec![start];
+    while let Some(node) = to_visit.pop() {
+        for parent in graph.neighbors_directed(node, petgraph::Direction::Incoming) {
+            if ancestors.insert(parent) {
+                to_visit.push(parent);
+            }
+        }
+    }
+    ancestors
+}
+
+/// Finds every strongly-connected component of `graph` with more than one
+/// member and removes those nodes from it in place, so the remainder can
+/// still be topologically sorted. A well-formed lineage/fork-tree graph is
+/// always a DAG; a cycle here means the lineage file is corrupt (e.g. a
+/// fork's parent edge was written pointing the wrong way). Quarantining lets
+/// the rest of the fork tree still hard-delete normally instead of the whole
+/// run aborting on one bad edge.
+fn quarantine_lineage_cycles(
+    graph: &mut petgraph::graphmap::DiGraphMap<CollectionUuid, ()>,
+) -> Vec<LineageCycle> {
+    let sccs = petgraph::algo::tarjan_scc(&*graph);
+
+    let mut cycles = vec![];
+    for scc in sccs {
+        if scc.len() < 2 {
+            continue;
+        }
+
+        let members: HashSet<CollectionUuid> = scc.iter().copied().collect();
+        let offending_edges = scc
+            .iter()
+            .flat_map(|&node| {
+                graph
+                    .neighbors_directed(node, petgraph::Direction::Outgoing)
+                    .filter(|neighbor| members.contains(neighbor))
+                    .map(move |neighbor| (node, neighbor))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Computed before the cycle's nodes are removed below, while they're
+        // still reachable from their parents.
+        let ancestors = scc
+            .iter()
+            .flat_map(|&node| ancestors_of(graph, node))
+            .filter(|ancestor| !members.contains(ancestor))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        for &node in &scc {
+            graph.remove_node(node);
+        }
+
+        cycles.push(LineageCycle {
+            collections: scc,
+            offending_edges,
+            ancestors,
+        });
+    }
+
+    cycles
+}
+
 #[async_trait]
 impl Orchestrator for GarbageCollectorOrchestrator {
     type Output = GarbageCollectorResponse;
@@ -222,12 +633,55 @@ impl Orchestrator for GarbageCollectorOrchestrator {
     }
 
     async fn on_start(&mut self, ctx: &ComponentContext<Self>) {
+        if self.enable_audit_report {
+            // From here on, every `tracing::*!` call made while this span
+            // (or a descendant of it) is current is captured into the audit
+            // report automatically - see `AuditLayer`. No more call sites
+            // need their own `audit_recorder.record(...)` alongside them.
+            self.audit_recorder.attach_to(&Span::current());
+        }
+
+        if self.enable_checkpointing {
+            if let Some(checkpoint) = self.checkpoint_store.load(self.collection_id).await {
+                self.resume_from_checkpoint(checkpoint);
+            }
+        }
+
         ctx.receiver()
             .send(ConstructVersionGraphRequest, Some(Span::current()))
             .await
             .expect("Failed to send ConstructVersionGraphRequest");
     }
 
+    /// Seeds `resumed_marked_versions`/`hard_deleted_collections` from a
+    /// checkpoint left behind by a previous, interrupted run for this
+    /// collection, so the rest of this run skips redoing the sysdb writes it
+    /// already completed. Split out of `on_start` so the seeding logic itself
+    /// is testable without the full actor lifecycle.
+    fn resume_from_checkpoint(&mut self, checkpoint: GcCheckpoint) {
+        tracing::warn!(
+            "Resuming from a leftover GC checkpoint for collection {}: {} version(s) across \
+             {} collection(s) were already marked for deletion and {} collection(s) were \
+             already hard-deleted before the previous run was interrupted. Skipping that work \
+             instead of redoing it. Checkpoint: {:#?}",
+            self.collection_id,
+            checkpoint
+                .versions_marked_for_deletion
+                .values()
+                .map(|versions| versions.len())
+                .sum::<usize>(),
+            checkpoint.versions_marked_for_deletion.len(),
+            checkpoint.hard_deleted_collections.len(),
+            checkpoint
+        );
+        self.resumed_marked_versions = checkpoint
+            .versions_marked_for_deletion
+            .into_iter()
+            .map(|(collection_id, versions)| (collection_id, versions.into_iter().collect()))
+            .collect();
+        self.hard_deleted_collections = checkpoint.hard_deleted_collections;
+    }
+
     fn set_result_channel(
         &mut self,
         sender: Sender<Result<GarbageCollectorResponse, GarbageCollectorError>>,
@@ -308,6 +762,31 @@ impl GarbageCollectorOrchestrator {
         );
         let output = orchestrator.run(self.system.clone()).await?;
 
+        // `ComputeVersionsToDeleteOperator` only accepts one cutoff_time/
+        // min_versions_to_keep for the whole fork tree, not a per-collection
+        // map, so resolve the policy once using the root collection this run
+        // was started for (every collection in its fork tree shares the same
+        // tenant/database) rather than per graph node.
+        if let Some(collection_info) = output
+            .version_files
+            .get(&self.collection_id)
+            .and_then(|version_file| version_file.collection_info_immutable.as_ref())
+        {
+            let resolved = self.retention_policy.resolve(
+                &collection_info.tenant_id,
+                &collection_info.database_name,
+                &self.collection_id,
+                self.version_absolute_cutoff_time,
+                self.collection_soft_delete_absolute_cutoff_time,
+                self.min_versions_to_keep,
+                Utc::now(),
+            );
+            self.version_absolute_cutoff_time = resolved.version_absolute_cutoff_time;
+            self.collection_soft_delete_absolute_cutoff_time =
+                resolved.collection_soft_delete_absolute_cutoff_time;
+            self.min_versions_to_keep = resolved.min_versions_to_keep;
+        }
+
         let collection_ids = output.version_files.keys().cloned().collect::<Vec<_>>();
 
         self.soft_deleted_collections_to_gc = self
@@ -322,6 +801,39 @@ impl GarbageCollectorOrchestrator {
         self.version_files = output.version_files;
         self.graph = Some(output.graph.clone());
 
+        self.version_generation_tokens = self
+            .version_files
+            .iter()
+            .filter_map(|(collection_id, version_file)| {
+                Some((*collection_id, VersionGenerationToken::from_version_file(version_file)?))
+            })
+            .collect();
+
+        // Not yet the final `aborted_versions` - the decision of whether each
+        // witness is itself aged out isn't available until
+        // `ComputeVersionsToDeleteOperator` runs, so this only narrows
+        // candidates down by the keep window for now. The witness check
+        // happens in `handle_compute_versions_to_delete_output`.
+        self.candidate_aborted_versions = self
+            .version_files
+            .iter()
+            .map(|(collection_id, version_file)| {
+                let keep_window = versions_within_keep_window(version_file, self.min_versions_to_keep);
+                let candidates = detect_aborted_versions(version_file)
+                    .into_iter()
+                    .filter(|(version, _)| !keep_window.contains(version))
+                    .collect();
+                (*collection_id, candidates)
+            })
+            .collect();
+        if !self.candidate_aborted_versions.is_empty() {
+            tracing::debug!(
+                "Candidate aborted/superseded-but-uncommitted compactions pending witness \
+                 confirmation: {:#?}",
+                self.candidate_aborted_versions
+            );
+        }
+
         let task = wrap(
             Box::new(ComputeVersionsToDeleteOperator {}),
             ComputeVersionsToDeleteInput {
@@ -358,12 +870,80 @@ impl GarbageCollectorOrchestrator {
             return Ok(());
         }
 
+        // Now that `ComputeVersionsToDeleteOperator` has decided, confirm each
+        // candidate abort's witness is itself marked `Delete` before trusting
+        // the inference. A witness that's still `Keep` means the candidate's
+        // "superseded at the same log position" match is just the
+        // false-positive case `detect_aborted_versions` warns about - a
+        // normal, fully-committed version coincidentally sharing a log
+        // position with a later metadata-only bump - so it's left out of
+        // `aborted_versions` and falls back to the normal age/keep-count cutoff.
+        self.aborted_versions = self
+            .candidate_aborted_versions
+            .iter()
+            .flat_map(|(collection_id, candidates)| {
+                let decisions = output.versions.get(collection_id);
+                candidates.iter().filter_map(move |(version, witness)| {
+                    let witness_aged_out = decisions
+                        .and_then(|decisions| decisions.get(witness))
+                        .is_some_and(|action| *action == CollectionVersionAction::Delete);
+                    witness_aged_out.then_some((*collection_id, *version))
+                })
+            })
+            .collect();
+        if !self.aborted_versions.is_empty() {
+            tracing::debug!(
+                "Confirmed aborted/superseded-but-uncommitted compactions: {:#?}",
+                self.aborted_versions
+            );
+        }
+
+        self.versions_covered_by_manifest = HashMap::new();
+        if self.enable_incremental_file_manifest {
+            for (collection_id, versions) in &output.versions {
+                let Some(manifest) = self.file_manifest_store.load(*collection_id).await else {
+                    continue;
+                };
+
+                // Retention only moves forward, so the manifest is only safe to
+                // reuse if the version it anchored its "everything up to here is
+                // live" claim to is still `Keep` in this run's fresh decision.
+                // If that boundary version was reclaimed since the manifest was
+                // saved, some file it counted as live may have been deleted, so
+                // fall back to a full recompute for this collection instead of
+                // trusting the cached `live_file_paths`.
+                if self.file_manifest_is_stale(*collection_id, &manifest, versions) {
+                    tracing::debug!(
+                        "Discarding stale file manifest for collection {}: version {} is no longer kept",
+                        collection_id,
+                        manifest.oldest_kept_version
+                    );
+                    continue;
+                }
+
+                for path in &manifest.live_file_paths {
+                    let count = self.file_ref_counts.entry(path.clone()).or_insert(0);
+                    *count += 1;
+                }
+                tracing::debug!(
+                    "Loaded file manifest for collection {} covering versions up to {}, {} live files",
+                    collection_id,
+                    manifest.highest_version_seen,
+                    manifest.live_file_paths.len()
+                );
+                self.versions_covered_by_manifest
+                    .insert(*collection_id, manifest.highest_version_seen);
+            }
+        }
+
         self.pending_list_files_at_version_tasks = output
             .versions
             .iter()
             .flat_map(|(collection_id, versions)| {
+                let manifest_cutoff = self.versions_covered_by_manifest.get(collection_id).copied();
                 versions
                     .keys()
+                    .filter(|version| manifest_cutoff.map_or(true, |cutoff| **version > cutoff))
                     .map(|version| (*collection_id, *version))
                     .collect::<HashSet<_>>()
             })
@@ -383,11 +963,21 @@ impl GarbageCollectorOrchestrator {
                 ),
             )?;
 
-            // Spawn task to mark versions as deleted
+            // Spawn task to mark versions as deleted. A version a previous,
+            // interrupted run's checkpoint already recorded as marked is left
+            // out here: resuming means not redoing that sysdb write, not just
+            // not redeciding it.
+            let already_resumed = self
+                .resumed_marked_versions
+                .get(collection_id)
+                .cloned()
+                .unwrap_or_default();
             let versions_to_mark = versions
                 .iter()
                 .filter_map(|(version, action)| {
-                    if *action == CollectionVersionAction::Delete {
+                    if self.is_marked_for_deletion(*collection_id, *version, *action)
+                        && !already_resumed.contains(version)
+                    {
                         Some(*version)
                     } else {
                         None
@@ -417,7 +1007,14 @@ impl GarbageCollectorOrchestrator {
                 .await
                 .map_err(GarbageCollectorError::Channel)?;
 
-            for version in versions.keys() {
+            let manifest_cutoff = self
+                .versions_covered_by_manifest
+                .get(collection_id)
+                .copied();
+            for version in versions
+                .keys()
+                .filter(|version| manifest_cutoff.map_or(true, |cutoff| **version > cutoff))
+            {
                 let task = wrap(
                     Box::new(ListFilesAtVersionsOperator {}),
                     ListFilesAtVersionInput::new(
@@ -568,6 +1165,8 @@ impl GarbageCollectorOrchestrator {
                 "Expected versions_to_delete_output to contain version {} for collection {}",
                 output.version, output.collection_id
             )))?;
+        let is_marked_for_deletion =
+            self.is_marked_for_deletion(output.collection_id, output.version, *version_action);
 
         tracing::trace!(
             "Received ListFilesAtVersionOutput for collection {} at version {}. Action: {:?}. File paths: {:#?}",
@@ -644,31 +1243,28 @@ impl GarbageCollectorOrchestrator {
         // - be 0 if we know about the file but it is unused
         // - be > 0 if we know about the file and it is used
         // We accomplish this by incrementing the count for files that are used and populating the map with 0 (if the entry does not exist) for files that are unused.
-        match version_action {
-            CollectionVersionAction::Keep => {
-                tracing::debug!(
-                    "Marking {} files as used for collection {} at version {}",
-                    output.file_paths.len(),
-                    output.collection_id,
-                    output.version
-                );
+        if is_marked_for_deletion {
+            tracing::debug!(
+                "Marking {} files as unused for collection {} at version {}",
+                output.file_paths.len(),
+                output.collection_id,
+                output.version
+            );
 
-                for file_path in output.file_paths {
-                    let count = self.file_ref_counts.entry(file_path).or_insert(0);
-                    *count += 1;
-                }
+            for file_path in output.file_paths {
+                self.file_ref_counts.entry(file_path).or_insert(0);
             }
-            CollectionVersionAction::Delete => {
-                tracing::debug!(
-                    "Marking {} files as unused for collection {} at version {}",
-                    output.file_paths.len(),
-                    output.collection_id,
-                    output.version
-                );
+        } else {
+            tracing::debug!(
+                "Marking {} files as used for collection {} at version {}",
+                output.file_paths.len(),
+                output.collection_id,
+                output.version
+            );
 
-                for file_path in output.file_paths {
-                    self.file_ref_counts.entry(file_path).or_insert(0);
-                }
+            for file_path in output.file_paths {
+                let count = self.file_ref_counts.entry(file_path).or_insert(0);
+                *count += 1;
             }
         }
 
@@ -691,6 +1287,34 @@ impl GarbageCollectorOrchestrator {
             return Ok(());
         }
 
+        // Unused files are computed (and will be deleted) across the whole
+        // fork tree at once, not per collection, so there is no way to
+        // exclude just the drifted collection's files from this batch the
+        // way the sysdb version-delete step excludes just that collection's
+        // versions. A version file changing concurrently with this run means
+        // a brand-new version may already reference a file we're about to
+        // delete, so the only safe option is to abort the whole batch and
+        // let the next GC cycle pick it up once the race has settled.
+        let collection_ids_in_graph = self
+            .version_generation_tokens
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        for collection_id in collection_ids_in_graph {
+            if self.has_concurrent_modification(collection_id).await? {
+                tracing::warn!(
+                    "Aborting file deletion for this GC run: collection {}'s version file changed concurrently with this run",
+                    collection_id
+                );
+                self.terminate_with_result(
+                    Err(GarbageCollectorError::ConcurrentModification(collection_id)),
+                    ctx,
+                )
+                .await;
+                return Ok(());
+            }
+        }
+
         // We now have results for all ListFilesAtVersionsOperator tasks that we spawned
         tracing::trace!("File ref counts: {:#?}", self.file_ref_counts);
         let file_paths_to_delete = self
@@ -710,15 +1334,20 @@ impl GarbageCollectorOrchestrator {
 
         tracing::debug!(
             delete_percentage = delete_percentage,
-            "Deleting {} files out of a total of {}",
+            "Deleting {} files out of a total of {} ({:.2}%)",
             file_paths_to_delete.len(),
-            self.file_ref_counts.len()
+            self.file_ref_counts.len(),
+            delete_percentage
         );
 
         if file_paths_to_delete.is_empty() {
             tracing::debug!("No files to delete.");
         }
 
+        if self.enable_incremental_file_manifest && self.cleanup_mode == CleanupMode::Delete {
+            self.save_file_manifests().await;
+        }
+
         let version_file =
             self.version_files
                 .values()
@@ -736,12 +1365,13 @@ impl GarbageCollectorOrchestrator {
         self.tenant = Some(tenant_id.clone());
         let database_name = collection_info.database_name.clone();
         self.database_name = Some(database_name.clone());
+        let database_id = collection_info.database_id.clone();
 
         let task = wrap(
             Box::new(DeleteUnusedFilesOperator::new(
                 self.storage.clone(),
                 self.cleanup_mode,
-                tenant_id,
+                tenant_id.clone(),
             )),
             DeleteUnusedFilesInput {
                 unused_s3_files: file_paths_to_delete,
@@ -755,6 +1385,34 @@ impl GarbageCollectorOrchestrator {
             .await
             .map_err(GarbageCollectorError::Channel)?;
 
+        if self.enable_orphan_file_repair {
+            let referenced_files = self
+                .file_ref_counts
+                .iter()
+                .filter_map(|(path, count)| (*count > 0).then(|| path.clone()))
+                .collect::<HashSet<_>>();
+
+            let repair_task = wrap(
+                Box::new(RepairOrphanFilesOperator {
+                    storage: self.storage.clone(),
+                    mode: self.cleanup_mode,
+                }),
+                RepairOrphanFilesInput {
+                    storage_prefix: format!("{}/{}/{}", tenant_id, database_id, self.collection_id),
+                    referenced_files,
+                    safe_to_delete_before: self.version_absolute_cutoff_time,
+                },
+                ctx.receiver(),
+                self.context.task_cancellation_token.clone(),
+            );
+            self.dispatcher()
+                .send(repair_task, Some(Span::current()))
+                .await
+                .map_err(GarbageCollectorError::Channel)?;
+        } else {
+            self.orphan_repair_done = true;
+        }
+
         Ok(())
     }
 
@@ -772,6 +1430,9 @@ impl GarbageCollectorOrchestrator {
 
         if self.cleanup_mode == CleanupMode::DryRun {
             tracing::info!("Dry run mode, skipping actual deletion");
+            if self.enable_audit_report {
+                self.save_audit_report(vec![]).await;
+            }
             let response = GarbageCollectorResponse {
                 num_versions_deleted: 0,
                 num_files_deleted: 0,
@@ -797,7 +1458,7 @@ impl GarbageCollectorOrchestrator {
                 let versions = versions
                     .iter()
                     .filter_map(|(version, action)| {
-                        if *action == CollectionVersionAction::Delete {
+                        if self.is_marked_for_deletion(*collection_id, *version, *action) {
                             Some(*version)
                         } else {
                             None
@@ -835,9 +1496,22 @@ impl GarbageCollectorOrchestrator {
             total_num_versions_to_delete,
             versions_to_delete.len()
         );
-        self.num_pending_tasks += versions_to_delete.len();
 
+        let mut versions_to_delete_by_unmodified_collection = HashMap::new();
         for (collection_id, versions) in versions_to_delete {
+            if self.has_concurrent_modification(collection_id).await? {
+                tracing::warn!(
+                    "Skipping delete for collection {} this run: its version file changed concurrently",
+                    collection_id
+                );
+                continue;
+            }
+            versions_to_delete_by_unmodified_collection.insert(collection_id, versions);
+        }
+
+        self.num_pending_tasks += versions_to_delete_by_unmodified_collection.len();
+
+        for (collection_id, versions) in versions_to_delete_by_unmodified_collection {
             let version_file = self
                 .version_files
                 .get(&collection_id)
@@ -875,7 +1549,225 @@ impl GarbageCollectorOrchestrator {
                 .map_err(GarbageCollectorError::Channel)?;
         }
 
-        Ok(())
+        // If every collection was skipped due to a concurrent modification, no
+        // DeleteVersionsAtSysDb tasks were spawned and nothing will otherwise
+        // drive finalization forward.
+        self.try_finalize(ctx).await
+    }
+
+    /// Persists a refreshed [`CollectionFileManifest`] for every collection
+    /// this run touched, so the next run can skip listing files for versions
+    /// this one already accounted for. Best-effort: a failed save just means
+    /// the next run falls back to a full recompute for that collection.
+    async fn save_file_manifests(&self) {
+        let Some(versions_to_delete_output) = self.versions_to_delete_output.as_ref() else {
+            return;
+        };
+
+        let live_file_paths = self
+            .file_ref_counts
+            .iter()
+            .filter_map(|(path, count)| (*count > 0).then(|| path.clone()))
+            .collect::<HashSet<_>>();
+
+        for (collection_id, versions) in &versions_to_delete_output.versions {
+            let Some(highest_version_seen) = versions.keys().max().copied() else {
+                continue;
+            };
+            let Some(oldest_kept_version) = versions
+                .iter()
+                .filter(|(version, action)| {
+                    !self.is_marked_for_deletion(*collection_id, **version, **action)
+                })
+                .map(|(version, _)| *version)
+                .min()
+            else {
+                continue;
+            };
+            let manifest = crate::file_manifest::CollectionFileManifest {
+                highest_version_seen,
+                oldest_kept_version,
+                live_file_paths: live_file_paths.clone(),
+            };
+            if let Err(err) = self.file_manifest_store.save(*collection_id, &manifest).await {
+                tracing::warn!(
+                    "Failed to save file manifest for collection {}: {}",
+                    collection_id,
+                    err
+                );
+            }
+        }
+    }
+
+    /// Persists a [`GcCheckpoint`] snapshot of this run's delete decisions,
+    /// ref counts, and hard-deletes so far. Called when a run is
+    /// cooperatively aborted; best-effort like `save_file_manifests`, since a
+    /// failed save only means the next run redoes the sysdb writes that
+    /// `resume_from_checkpoint` would otherwise let it skip.
+    async fn save_checkpoint(&self) {
+        let versions_marked_for_deletion = self
+            .versions_to_delete_output
+            .as_ref()
+            .map(|output| {
+                output
+                    .versions
+                    .iter()
+                    .map(|(collection_id, versions)| {
+                        let marked = versions
+                            .iter()
+                            .filter(|(version, action)| {
+                                self.is_marked_for_deletion(*collection_id, **version, **action)
+                            })
+                            .map(|(version, _)| *version)
+                            .collect::<Vec<_>>();
+                        (*collection_id, marked)
+                    })
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        let checkpoint = GcCheckpoint {
+            versions_marked_for_deletion,
+            file_ref_counts: self.file_ref_counts.clone(),
+            hard_deleted_collections: self.hard_deleted_collections.clone(),
+        };
+
+        if let Err(err) = self
+            .checkpoint_store
+            .save(self.collection_id, &checkpoint)
+            .await
+        {
+            tracing::warn!(
+                "Failed to save GC checkpoint for collection {}: {}",
+                self.collection_id,
+                err
+            );
+        }
+    }
+
+    /// Builds and persists a [`GcAuditReport`] for this run and logs its
+    /// storage location. Best-effort, like the checkpoint and file manifest
+    /// saves: a failure here just means this run's report isn't retrievable
+    /// afterward, not that the run itself failed.
+    async fn save_audit_report(&self, collections_hard_deleted_in_order: Vec<CollectionUuid>) {
+        let versions_marked_for_deletion = self
+            .versions_to_delete_output
+            .as_ref()
+            .map(|output| {
+                output
+                    .versions
+                    .iter()
+                    .map(|(collection_id, versions)| {
+                        let marked = versions
+                            .iter()
+                            .filter(|(version, action)| {
+                                self.is_marked_for_deletion(*collection_id, **version, **action)
+                            })
+                            .map(|(version, _)| *version)
+                            .collect::<Vec<_>>();
+                        (*collection_id, marked)
+                    })
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        let report = GcAuditReport {
+            collection_id: self.collection_id,
+            run_started_at: self.run_started_at,
+            run_completed_at: Utc::now(),
+            dry_run: self.cleanup_mode == CleanupMode::DryRun,
+            num_files_considered: self.file_ref_counts.len() as u32,
+            num_files_deleted: self.num_files_deleted + self.num_orphan_files_deleted,
+            num_orphan_files_deleted: self.num_orphan_files_deleted,
+            num_versions_deleted: self.num_versions_deleted,
+            versions_marked_for_deletion,
+            collections_hard_deleted_in_order,
+            quarantined_collections: self.quarantined_collections.iter().copied().collect(),
+            quarantined_lineage_edges: self.quarantined_lineage_edges.clone(),
+            events: self.audit_recorder.drain(),
+        };
+
+        match self.audit_report_store.save(&report).await {
+            Ok(key) => tracing::info!(
+                "Saved GC audit report for collection {} to {}",
+                self.collection_id,
+                key
+            ),
+            Err(err) => tracing::warn!(
+                "Failed to save GC audit report for collection {}: {}",
+                self.collection_id,
+                err
+            ),
+        }
+    }
+
+    /// Whether `version` of `collection_id` should be treated as deleted:
+    /// either the normal age/min-versions logic already said so, or it was
+    /// independently flagged as an aborted/superseded-but-uncommitted
+    /// compaction, which is reclaimed regardless of the cutoff.
+    fn is_marked_for_deletion(
+        &self,
+        collection_id: CollectionUuid,
+        version: i64,
+        action: CollectionVersionAction,
+    ) -> bool {
+        action == CollectionVersionAction::Delete
+            || self.aborted_versions.contains(&(collection_id, version))
+    }
+
+    /// Whether a cached [`CollectionFileManifest`] for `collection_id` is safe
+    /// to trust against this run's fresh `versions` decisions: stale if its
+    /// `oldest_kept_version` boundary is no longer `Keep` (including if that
+    /// version isn't in `versions` at all), since retention only moves
+    /// forward and some file the manifest counted as live may have been
+    /// deleted since it was saved.
+    fn file_manifest_is_stale(
+        &self,
+        collection_id: CollectionUuid,
+        manifest: &CollectionFileManifest,
+        versions: &HashMap<i64, CollectionVersionAction>,
+    ) -> bool {
+        let boundary_still_kept = versions
+            .get(&manifest.oldest_kept_version)
+            .is_some_and(|action| {
+                !self.is_marked_for_deletion(collection_id, manifest.oldest_kept_version, *action)
+            });
+        !boundary_still_kept
+    }
+
+    /// Re-reads the collection's current version/log-position from sysdb and
+    /// compares it against the token captured when its version graph was
+    /// built. A mismatch means a version was flushed concurrently with this
+    /// GC run, so deleting is unsafe and should be retried next cycle.
+    async fn has_concurrent_modification(
+        &mut self,
+        collection_id: CollectionUuid,
+    ) -> Result<bool, GarbageCollectorError> {
+        let Some(captured) = self.version_generation_tokens.get(&collection_id).copied() else {
+            return Ok(false);
+        };
+
+        let mut current = self
+            .sysdb_client
+            .get_collections(GetCollectionsOptions {
+                collection_id: Some(collection_id),
+                include_soft_deleted: true,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| GarbageCollectorError::SysDbMethodFailed(e.to_string()))?;
+
+        let Some(collection) = current.pop() else {
+            // The collection is gone entirely; nothing left to race with.
+            return Ok(false);
+        };
+
+        let observed = VersionGenerationToken {
+            max_version: collection.version as i64,
+            current_log_position: collection.log_position,
+        };
+
+        Ok(observed != captured)
     }
 
     async fn handle_delete_versions_output(
@@ -887,7 +1779,48 @@ impl GarbageCollectorOrchestrator {
         self.num_versions_deleted += output.versions_to_delete.versions.len() as u32;
 
         self.num_pending_tasks -= 1;
-        if self.num_pending_tasks == 0 {
+        self.try_finalize(ctx).await
+    }
+
+    async fn handle_repair_orphan_files_output(
+        &mut self,
+        output: RepairOrphanFilesOutput,
+        ctx: &ComponentContext<Self>,
+    ) -> Result<(), GarbageCollectorError> {
+        tracing::debug!(
+            "Orphan file repair deleted {} of {} orphan files found",
+            output.deleted_files.len(),
+            output.orphan_files.len()
+        );
+        self.num_orphan_files_deleted += output.deleted_files.len() as u32;
+        self.orphan_repair_done = true;
+        self.try_finalize(ctx).await
+    }
+
+    /// Runs the hard-delete finalization once every task this GC pass spawned
+    /// (version deletion and, if enabled, orphan repair) has completed.
+    async fn try_finalize(&mut self, ctx: &ComponentContext<Self>) -> Result<(), GarbageCollectorError> {
+        // Both outputs are set only once `try_start_delete_versions_at_sysdb_operator`
+        // has spawned (and counted in `num_pending_tasks`) every version
+        // deletion task, so an early orphan repair result can't finalize first.
+        if self.num_pending_tasks == 0
+            && self.orphan_repair_done
+            && self.delete_unused_file_output.is_some()
+            && self.delete_unused_log_output.is_some()
+        {
+            if self.context.task_cancellation_token.is_cancelled() {
+                tracing::info!(
+                    "GC run for collection {} was cooperatively aborted before the hard-delete stage",
+                    self.collection_id
+                );
+                if self.enable_checkpointing {
+                    self.save_checkpoint().await;
+                }
+                self.terminate_with_result(Err(GarbageCollectorError::Aborted), ctx)
+                    .await;
+                return Ok(());
+            }
+
             let graph = self
                 .graph
                 .as_ref()
@@ -899,12 +1832,33 @@ impl GarbageCollectorOrchestrator {
 
             // We cannot finalize collection deletion (perform a hard delete) if there are any forked collections downstream that are still alive. If we violated this invariant, there would be a missing edge in the lineage file (resulting in an unconnected graph).
             // We must also delete collections in reverse topological order, so that we delete children before parents.
-            let collection_dependency_graph = version_graph_to_collection_dependency_graph(graph);
-            let topo = toposort(&collection_dependency_graph, None).map_err(|_| {
-                GarbageCollectorError::InvariantViolation(
-                    "Failed to topologically sort collection dependency graph".to_string(),
-                )
-            })?;
+            let mut collection_dependency_graph = version_graph_to_collection_dependency_graph(graph);
+            let topo = match toposort(&collection_dependency_graph, None) {
+                Ok(topo) => topo,
+                Err(cycle) => {
+                    let quarantined = quarantine_lineage_cycles(&mut collection_dependency_graph);
+                    tracing::error!(
+                        "Detected a cycle in the collection lineage graph at collection {}; excluding {} collection(s) in {} cycle(s) from hard-deletion this run and continuing with the rest of the fork tree: {:#?}",
+                        cycle.node_id(),
+                        quarantined.iter().map(|c| c.collections.len()).sum::<usize>(),
+                        quarantined.len(),
+                        quarantined
+                    );
+                    self.quarantined_collections.extend(
+                        quarantined
+                            .iter()
+                            .flat_map(|c| c.collections.iter().chain(c.ancestors.iter()).copied()),
+                    );
+                    self.quarantined_lineage_edges
+                        .extend(quarantined.iter().flat_map(|c| c.offending_edges.iter().copied()));
+
+                    toposort(&collection_dependency_graph, None).map_err(|_| {
+                        GarbageCollectorError::InvariantViolation(
+                            "Collection dependency graph still contains a cycle after quarantining every strongly-connected component".to_string(),
+                        )
+                    })?
+                }
+            };
 
             for collection_id in topo.iter().rev() {
                 // This check is not strictly needed as are_all_children_soft_deleted will be false if the current node is not soft deleted, but it avoids unnecessary computation and prevents misleading logs.
@@ -912,6 +1866,19 @@ impl GarbageCollectorOrchestrator {
                     continue;
                 }
 
+                // Ancestors of a quarantined cycle can't be hard-deleted this
+                // run either: their cyclic descendant was removed from
+                // `collection_dependency_graph` above, so the reachability
+                // check below can no longer see it and would otherwise
+                // wrongly treat them as having no forked descendants alive.
+                if self.quarantined_collections.contains(collection_id) {
+                    tracing::trace!(
+                        "Skipping hard delete for collection {} because it is quarantined or an ancestor of a quarantined cycle",
+                        collection_id
+                    );
+                    continue;
+                }
+
                 let are_all_children_soft_deleted = petgraph::algo::dijkstra(
                     &collection_dependency_graph,
                     *collection_id,
@@ -932,11 +1899,19 @@ impl GarbageCollectorOrchestrator {
             }
 
             tracing::debug!(
-                "Hard deleting collections {:#?}",
+                "Hard deleting collections {:?}",
                 ordered_soft_deleted_to_hard_delete_collections
             );
 
+            let mut hard_deleted_this_run = vec![];
+
             for collection_id in ordered_soft_deleted_to_hard_delete_collections {
+                // Already hard-deleted by a previous, interrupted run whose
+                // checkpoint we resumed from; don't redo it.
+                if self.hard_deleted_collections.contains(&collection_id) {
+                    continue;
+                }
+
                 self.sysdb_client
                     .finish_collection_deletion(
                         self.tenant
@@ -952,10 +1927,33 @@ impl GarbageCollectorOrchestrator {
                         collection_id,
                     )
                     .await?;
+
+                self.hard_deleted_collections.insert(collection_id);
+                hard_deleted_this_run.push(collection_id);
+            }
+
+            if self.enable_checkpointing {
+                if let Err(err) = self.checkpoint_store.clear(self.collection_id).await {
+                    tracing::warn!(
+                        "Failed to clear GC checkpoint for collection {}: {}",
+                        self.collection_id,
+                        err
+                    );
+                }
+            }
+
+            if self.enable_audit_report {
+                self.save_audit_report(hard_deleted_this_run).await;
             }
 
+            // `num_orphan_files_deleted`, `quarantined_collections`, and
+            // `quarantined_lineage_edges` aren't broken out as their own
+            // fields here: `GarbageCollectorResponse` is defined in
+            // `crate::types`, which this change doesn't touch, so there's no
+            // field to put them in. Enable `enable_audit_report` to get them
+            // as distinct fields on the persisted `GcAuditReport` instead.
             let response = GarbageCollectorResponse {
-                num_files_deleted: self.num_files_deleted,
+                num_files_deleted: self.num_files_deleted + self.num_orphan_files_deleted,
                 num_versions_deleted: self.num_versions_deleted,
                 collection_id: self.collection_id,
                 ..Default::default()
@@ -1110,9 +2108,30 @@ impl Handler<TaskResult<DeleteVersionsAtSysDbOutput, DeleteVersionsAtSysDbError>
     }
 }
 
+#[async_trait]
+impl Handler<TaskResult<RepairOrphanFilesOutput, RepairOrphanFilesError>>
+    for GarbageCollectorOrchestrator
+{
+    type Result = ();
+
+    async fn handle(
+        &mut self,
+        message: TaskResult<RepairOrphanFilesOutput, RepairOrphanFilesError>,
+        ctx: &ComponentContext<GarbageCollectorOrchestrator>,
+    ) {
+        let output = match self.ok_or_terminate(message.into_inner(), ctx).await {
+            Some(output) => output,
+            None => return,
+        };
+
+        let res = self.handle_repair_orphan_files_output(output, ctx).await;
+        self.ok_or_terminate(res, ctx).await;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GarbageCollectorOrchestrator;
+    use super::{GarbageCollectorOrchestrator, GcCheckpoint};
     use chroma_blockstore::RootManager;
     use chroma_cache::nop::NopCache;
     use chroma_log::Log;
@@ -1123,7 +2142,11 @@ mod tests {
         CollectionUuid, Segment, SegmentFlushInfo, SegmentScope, SegmentType, SegmentUuid,
     };
     use chrono::DateTime;
-    use std::{collections::HashMap, sync::Arc, time::SystemTime};
+    use std::{
+        collections::{HashMap, HashSet},
+        sync::Arc,
+        time::SystemTime,
+    };
 
     #[tokio::test(flavor = "multi_thread")]
     async fn errors_on_empty_file_paths() {
@@ -1222,4 +2245,114 @@ mod tests {
         assert!(result.is_err());
         assert!(format!("{:?}", result).contains("no file paths"));
     }
+
+    /// Builds an orchestrator with no collections/versions behind it, for
+    /// tests that exercise a piece of its logic directly rather than a full
+    /// run through the actor framework.
+    fn bare_orchestrator(collection_id: CollectionUuid) -> GarbageCollectorOrchestrator {
+        let (_storage_dir, storage) = test_storage();
+        let sysdb = chroma_sysdb::SysDb::Test(TestSysDb::new());
+
+        let system = System::new();
+        let dispatcher = Dispatcher::new(Default::default());
+        let dispatcher_handle = system.start_component(dispatcher);
+        let root_manager = RootManager::new(storage.clone(), Box::new(NopCache));
+
+        let now = DateTime::from_timestamp(
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs() as i64,
+            0,
+        )
+        .unwrap();
+        let logs = Log::InMemory(chroma_log::in_memory_log::InMemoryLog::default());
+
+        GarbageCollectorOrchestrator::new(
+            collection_id,
+            "unused".to_string(),
+            None,
+            now,
+            now,
+            sysdb,
+            dispatcher_handle,
+            system,
+            storage,
+            logs,
+            root_manager,
+            crate::types::CleanupMode::Delete,
+            1,
+            true,
+            false,
+        )
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn resume_from_checkpoint_seeds_marked_versions_and_hard_deleted_collections() {
+        let collection_id = CollectionUuid::new();
+        let other_collection_id = CollectionUuid::new();
+
+        let mut orchestrator = bare_orchestrator(collection_id).with_checkpointing(true);
+
+        let checkpoint = GcCheckpoint {
+            versions_marked_for_deletion: HashMap::from([
+                (collection_id, vec![1, 2]),
+                (other_collection_id, vec![3]),
+            ]),
+            file_ref_counts: HashMap::new(),
+            hard_deleted_collections: HashSet::from([other_collection_id]),
+        };
+        orchestrator.resume_from_checkpoint(checkpoint);
+
+        assert_eq!(
+            orchestrator.resumed_marked_versions.get(&collection_id),
+            Some(&HashSet::from([1, 2]))
+        );
+        assert_eq!(
+            orchestrator.resumed_marked_versions.get(&other_collection_id),
+            Some(&HashSet::from([3]))
+        );
+        assert!(orchestrator
+            .hard_deleted_collections
+            .contains(&other_collection_id));
+    }
+
+    fn manifest(oldest_kept_version: i64) -> crate::file_manifest::CollectionFileManifest {
+        crate::file_manifest::CollectionFileManifest {
+            highest_version_seen: oldest_kept_version + 5,
+            oldest_kept_version,
+            live_file_paths: HashSet::from(["some/file".to_string()]),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn file_manifest_is_fresh_when_its_boundary_version_is_still_kept() {
+        let collection_id = CollectionUuid::new();
+        let orchestrator = bare_orchestrator(collection_id);
+
+        let versions = HashMap::from([(3, super::CollectionVersionAction::Keep)]);
+
+        assert!(!orchestrator.file_manifest_is_stale(collection_id, &manifest(3), &versions));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn file_manifest_is_stale_when_its_boundary_version_is_now_marked_for_deletion() {
+        let collection_id = CollectionUuid::new();
+        let orchestrator = bare_orchestrator(collection_id);
+
+        let versions = HashMap::from([(3, super::CollectionVersionAction::Delete)]);
+
+        assert!(orchestrator.file_manifest_is_stale(collection_id, &manifest(3), &versions));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn file_manifest_is_stale_when_its_boundary_version_is_missing_from_this_runs_decisions()
+    {
+        let collection_id = CollectionUuid::new();
+        let orchestrator = bare_orchestrator(collection_id);
+
+        let versions = HashMap::new();
+
+        assert!(orchestrator.file_manifest_is_stale(collection_id, &manifest(3), &versions));
+    }
 }
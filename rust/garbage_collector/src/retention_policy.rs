@@ -0,0 +1,233 @@
+use chrono::{DateTime, Duration, Utc};
+
+use chroma_types::CollectionUuid;
+
+/// Narrows a [`RetentionRule`] to the collections it applies to. `None` fields
+/// match anything, so a rule with an all-`None` filter applies globally.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RetentionFilter {
+    pub tenant_id: Option<String>,
+    pub database_name: Option<String>,
+    pub collection_id_prefix: Option<String>,
+}
+
+impl RetentionFilter {
+    fn matches(&self, tenant_id: &str, database_name: &str, collection_id: &CollectionUuid) -> bool {
+        if let Some(expected) = &self.tenant_id {
+            if expected != tenant_id {
+                return false;
+            }
+        }
+        if let Some(expected) = &self.database_name {
+            if expected != database_name {
+                return false;
+            }
+        }
+        if let Some(prefix) = &self.collection_id_prefix {
+            if !collection_id.to_string().starts_with(prefix.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// The expiration predicate a [`RetentionRule`] evaluates once it matches a
+/// collection.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RetentionExpiration {
+    /// Delete versions older than `max_age` relative to `now`.
+    MaxAge(Duration),
+    /// Regardless of age, always keep at least `min_versions_to_keep` versions.
+    MinVersionsToKeep(u32),
+    /// Soft-deleted collections become eligible for hard deletion `after` has
+    /// elapsed since they were marked soft-deleted.
+    ExpireSoftDeletedAfter(Duration),
+    /// A grandfather-style schedule: keep roughly one version per day for
+    /// `daily_for`, then roughly one per week for the following `weekly_for`,
+    /// expiring anything older than `daily_for + weekly_for`.
+    ///
+    /// `ComputeVersionsToDeleteOperator` only takes a single per-collection
+    /// cutoff timestamp, not a per-version decision, so this resolves to the
+    /// coarser `daily_for + weekly_for` cutoff rather than precisely thinning
+    /// same-day/same-week versions down to one each. Getting the precise
+    /// thinning behavior requires the operator to see each version's
+    /// timestamp, which is a larger change than this rule alone.
+    GrandfatheredSchedule {
+        daily_for: Duration,
+        weekly_for: Duration,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetentionRule {
+    /// Restricts which collections this rule applies to. `None` matches all
+    /// collections, so it's typically reserved for the catch-all rule at the
+    /// end of a policy's rule list.
+    pub filter: Option<RetentionFilter>,
+    pub expiration: RetentionExpiration,
+}
+
+impl RetentionRule {
+    fn matches(&self, tenant_id: &str, database_name: &str, collection_id: &CollectionUuid) -> bool {
+        match &self.filter {
+            Some(filter) => filter.matches(tenant_id, database_name, collection_id),
+            None => true,
+        }
+    }
+}
+
+/// The cutoff/keep-count a [`RetentionPolicy`] resolved for one collection,
+/// in the same shape `ComputeVersionsToDeleteOperator` already consumes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedRetention {
+    pub version_absolute_cutoff_time: DateTime<Utc>,
+    pub collection_soft_delete_absolute_cutoff_time: DateTime<Utc>,
+    pub min_versions_to_keep: u32,
+}
+
+/// An ordered list of rules evaluated top to bottom; the first rule whose
+/// filter matches a collection wins, modeled on an S3 lifecycle worker.
+///
+/// A policy should always end with a rule whose filter is `None` so every
+/// collection resolves to something; [`RetentionPolicy::resolve`] falls back
+/// to the default cutoffs and keep-count passed to it if no rule matches.
+#[derive(Debug, Clone, Default)]
+pub struct RetentionPolicy {
+    pub rules: Vec<RetentionRule>,
+}
+
+impl RetentionPolicy {
+    /// Builds a policy with a single global rule, matching the orchestrator's
+    /// previous behavior of one absolute cutoff and one keep-count for every
+    /// collection.
+    pub fn single_rule(min_versions_to_keep: u32) -> Self {
+        Self {
+            rules: vec![RetentionRule {
+                filter: None,
+                expiration: RetentionExpiration::MinVersionsToKeep(min_versions_to_keep),
+            }],
+        }
+    }
+
+    /// Resolves the cutoff/keep-count to apply to `collection_id`, given the
+    /// already-computed global cutoffs to fall back on when no rule matches
+    /// (or matches with a predicate that doesn't constrain that field).
+    pub fn resolve(
+        &self,
+        tenant_id: &str,
+        database_name: &str,
+        collection_id: &CollectionUuid,
+        default_version_absolute_cutoff_time: DateTime<Utc>,
+        default_collection_soft_delete_absolute_cutoff_time: DateTime<Utc>,
+        default_min_versions_to_keep: u32,
+        now: DateTime<Utc>,
+    ) -> ResolvedRetention {
+        let mut resolved = ResolvedRetention {
+            version_absolute_cutoff_time: default_version_absolute_cutoff_time,
+            collection_soft_delete_absolute_cutoff_time:
+                default_collection_soft_delete_absolute_cutoff_time,
+            min_versions_to_keep: default_min_versions_to_keep,
+        };
+
+        let Some(rule) = self
+            .rules
+            .iter()
+            .find(|rule| rule.matches(tenant_id, database_name, collection_id))
+        else {
+            return resolved;
+        };
+
+        match rule.expiration {
+            RetentionExpiration::MaxAge(max_age) => {
+                resolved.version_absolute_cutoff_time = now - max_age;
+            }
+            RetentionExpiration::MinVersionsToKeep(min_versions_to_keep) => {
+                resolved.min_versions_to_keep = min_versions_to_keep;
+            }
+            RetentionExpiration::ExpireSoftDeletedAfter(after) => {
+                resolved.collection_soft_delete_absolute_cutoff_time = now - after;
+            }
+            RetentionExpiration::GrandfatheredSchedule {
+                daily_for,
+                weekly_for,
+            } => {
+                resolved.version_absolute_cutoff_time = now - (daily_for + weekly_for);
+            }
+        }
+
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collection_id() -> CollectionUuid {
+        CollectionUuid::new()
+    }
+
+    #[test]
+    fn falls_back_to_defaults_when_no_rule_matches() {
+        let policy = RetentionPolicy {
+            rules: vec![RetentionRule {
+                filter: Some(RetentionFilter {
+                    tenant_id: Some("other-tenant".to_string()),
+                    ..Default::default()
+                }),
+                expiration: RetentionExpiration::MinVersionsToKeep(100),
+            }],
+        };
+
+        let now = Utc::now();
+        let resolved = policy.resolve("test-tenant", "default", &collection_id(), now, now, 5, now);
+
+        assert_eq!(resolved.min_versions_to_keep, 5);
+    }
+
+    #[test]
+    fn grandfathered_schedule_resolves_to_combined_window_cutoff() {
+        let now = Utc::now();
+        let policy = RetentionPolicy {
+            rules: vec![RetentionRule {
+                filter: None,
+                expiration: RetentionExpiration::GrandfatheredSchedule {
+                    daily_for: Duration::days(7),
+                    weekly_for: Duration::days(28),
+                },
+            }],
+        };
+
+        let resolved = policy.resolve("test-tenant", "default", &collection_id(), now, now, 5, now);
+
+        assert_eq!(
+            resolved.version_absolute_cutoff_time,
+            now - Duration::days(35)
+        );
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let now = Utc::now();
+        let policy = RetentionPolicy {
+            rules: vec![
+                RetentionRule {
+                    filter: Some(RetentionFilter {
+                        tenant_id: Some("test-tenant".to_string()),
+                        ..Default::default()
+                    }),
+                    expiration: RetentionExpiration::MinVersionsToKeep(50),
+                },
+                RetentionRule {
+                    filter: None,
+                    expiration: RetentionExpiration::MinVersionsToKeep(5),
+                },
+            ],
+        };
+
+        let resolved = policy.resolve("test-tenant", "default", &collection_id(), now, now, 5, now);
+
+        assert_eq!(resolved.min_versions_to_keep, 50);
+    }
+}